@@ -26,9 +26,129 @@
 /// We then call `A` on `B::<SomeType>`.
 /// Because of rust dereferencing rules, if `SomeType` is `SomeTrait`, then we call `A` on `B<T>` which is true.
 /// However, if `SomeType` is not `SomeTrait`, we dereference `B<T>` into `()` and call `A` on that, which is false
+///
+/// The second argument can also be a boolean expression over several traits, combining
+/// leaves with `&` (and), `|` (or) and `!` (not), with the usual precedence (`!` tightest,
+/// then `&`, then `|`) and parentheses for grouping:
+///
+/// ```
+/// use is_trait::is_trait;
+///
+/// assert!(is_trait!(u32, Copy & Send));
+/// assert!(is_trait!(u32, Clone | Copy));
+/// assert!(!is_trait!(std::rc::Rc<u32>, !Clone));
+/// assert!(is_trait!(u32, (Copy & Send) | Default));
+/// ```
+///
+/// Precedence is pinned below with marker traits chosen so that the correct grouping and
+/// the wrong one give *different* results, so a regression that flips precedence would
+/// fail these assertions rather than silently pass:
+///
+/// ```
+/// use is_trait::is_trait;
+///
+/// struct Neither;
+/// struct OnlyGamma;
+/// trait Alpha {}
+/// trait Beta {}
+/// trait Gamma {}
+/// impl Gamma for OnlyGamma {}
+///
+/// // `&` binds tighter than `|`, so `Alpha & Beta | Gamma` is `(Alpha & Beta) | Gamma`:
+/// // (false & false) | true == true, whereas the wrong grouping `Alpha & (Beta | Gamma)`
+/// // gives false & (false | true) == false.
+/// assert!(is_trait!(OnlyGamma, Alpha & Beta | Gamma));
+/// assert!(!is_trait!(OnlyGamma, Alpha & (Beta | Gamma)));
+/// assert_ne!(
+///     is_trait!(OnlyGamma, Alpha & Beta | Gamma),
+///     is_trait!(OnlyGamma, Alpha & (Beta | Gamma))
+/// );
+///
+/// // `!` binds tighter than `&`, so `!Alpha & Beta` is `(!Alpha) & Beta`:
+/// // true & false == false, whereas the wrong grouping `!(Alpha & Beta)` gives
+/// // !(false & false) == true.
+/// assert!(!is_trait!(Neither, !Alpha & Beta));
+/// assert!(is_trait!(Neither, !(Alpha & Beta)));
+/// assert_ne!(
+///     is_trait!(Neither, !Alpha & Beta),
+///     is_trait!(Neither, !(Alpha & Beta))
+/// );
+/// ```
+///
+/// A leaf trait isn't limited to a bare path: generic arguments, associated-type equality
+/// constraints and lifetimes are all forwarded verbatim into the generated `where` clause,
+/// so parameterized trait bounds work too:
+///
+/// ```
+/// use is_trait::is_trait;
+///
+/// assert!(is_trait!(String, From<&'static str>));
+/// assert!(is_trait!(std::vec::IntoIter<u32>, Iterator<Item = u32>));
+/// ```
 #[macro_export]
 macro_rules! is_trait {
-    ($type:ty, $trait:path) => {{
+    ($type:ty, $($expr:tt)+) => {
+        $crate::is_trait!(@or $type, [] [] $($expr)+)
+    };
+
+    // Split on `|`, the lowest-precedence operator, but only outside any `<...>` generic
+    // argument list (tracked by the `[$($depth)*]` marker stack), so a bare `&` or `|`
+    // buried inside a bound like `From<&str>` isn't mistaken for a combinator.
+    (@or $type:ty, [] [$($lhs:tt)*] | $($rhs:tt)+) => {
+        ($crate::is_trait!(@and $type, [] [] $($lhs)*) || $crate::is_trait!(@or $type, [] [] $($rhs)+))
+    };
+    (@or $type:ty, [$($depth:tt)*] [$($acc:tt)*] < $($rest:tt)*) => {
+        $crate::is_trait!(@or $type, [# $($depth)*] [$($acc)* <] $($rest)*)
+    };
+    (@or $type:ty, [# $($depth:tt)*] [$($acc:tt)*] > $($rest:tt)*) => {
+        $crate::is_trait!(@or $type, [$($depth)*] [$($acc)* >] $($rest)*)
+    };
+    // `>>` closes two nesting levels at once: the lexer joins adjacent `>`s into a single
+    // `Shr` token (e.g. the end of `Vec<Vec<u32>>`), so it can't be matched as two `>` tts.
+    (@or $type:ty, [# # $($depth:tt)*] [$($acc:tt)*] >> $($rest:tt)*) => {
+        $crate::is_trait!(@or $type, [$($depth)*] [$($acc)* > >] $($rest)*)
+    };
+    (@or $type:ty, [$($depth:tt)*] [$($acc:tt)*] $tt:tt $($rest:tt)*) => {
+        $crate::is_trait!(@or $type, [$($depth)*] [$($acc)* $tt] $($rest)*)
+    };
+    (@or $type:ty, [$($depth:tt)*] [$($acc:tt)*]) => {
+        $crate::is_trait!(@and $type, [] [] $($acc)*)
+    };
+
+    // Split on `&`, with the same `<...>` depth tracking.
+    (@and $type:ty, [] [$($lhs:tt)*] & $($rhs:tt)+) => {
+        ($crate::is_trait!(@not $type, $($lhs)*) && $crate::is_trait!(@and $type, [] [] $($rhs)+))
+    };
+    (@and $type:ty, [$($depth:tt)*] [$($acc:tt)*] < $($rest:tt)*) => {
+        $crate::is_trait!(@and $type, [# $($depth)*] [$($acc)* <] $($rest)*)
+    };
+    (@and $type:ty, [# $($depth:tt)*] [$($acc:tt)*] > $($rest:tt)*) => {
+        $crate::is_trait!(@and $type, [$($depth)*] [$($acc)* >] $($rest)*)
+    };
+    // See the matching `>>` rule in `@or` above.
+    (@and $type:ty, [# # $($depth:tt)*] [$($acc:tt)*] >> $($rest:tt)*) => {
+        $crate::is_trait!(@and $type, [$($depth)*] [$($acc)* > >] $($rest)*)
+    };
+    (@and $type:ty, [$($depth:tt)*] [$($acc:tt)*] $tt:tt $($rest:tt)*) => {
+        $crate::is_trait!(@and $type, [$($depth)*] [$($acc)* $tt] $($rest)*)
+    };
+    (@and $type:ty, [$($depth:tt)*] [$($acc:tt)*]) => {
+        $crate::is_trait!(@not $type, $($acc)*)
+    };
+
+    // Strip a leading `!`.
+    (@not $type:ty, ! $($rest:tt)+) => {
+        (!$crate::is_trait!(@not $type, $($rest)+))
+    };
+    // A single parenthesized sub-expression: recurse from the top.
+    (@not $type:ty, ($($inner:tt)+)) => {
+        $crate::is_trait!(@or $type, [] [] $($inner)+)
+    };
+    // Leaf: a trait bound, forwarded verbatim into the generated `where` clause. Matched
+    // as raw token trees (rather than `$trait:path`) so generic arguments, associated-type
+    // equality constraints and lifetimes are all accepted, e.g. `From<&str>` or
+    // `Iterator<Item = u32>`.
+    (@not $type:ty, $($trait:tt)+) => {{
         trait A {
             fn is(&self) -> bool;
         }
@@ -44,7 +164,7 @@ macro_rules! is_trait {
 
         impl<T: ?Sized> A for B<T>
         where
-            T: $trait,
+            T: $($trait)+,
         {
             fn is(&self) -> bool {
                 true
@@ -96,3 +216,156 @@ macro_rules! const_is_trait {
         B::<$type>(core::marker::PhantomData).is()
     }};
 }
+
+/// Like [`const_is_trait`] but produces a genuine `const bool` on stable Rust, without
+/// relying on `const` trait impls.
+///
+/// Under the hood, this macro creates a fallback trait `Fallback` with an associated
+/// const `HAS` defaulting to `false`, blanket-implemented for every type, and a struct
+/// `Test<T>` with an inherent associated const `HAS` that only exists when `T: SomeTrait`.
+///
+/// `Test::<SomeType>::HAS` then resolves to the inherent const (`true`) when `SomeType`
+/// implements the trait, since inherent items take priority over trait items, and falls
+/// back to `Fallback::HAS` (`false`) otherwise.
+///
+/// Because the whole expression is a `const`, it can be used anywhere a constant is
+/// required, such as `const` generics or array lengths:
+///
+/// ```
+/// use is_trait::stable_is_trait;
+///
+/// const IS_COPY: bool = stable_is_trait!(u32, Copy);
+/// let _array: [u8; stable_is_trait!(u32, Copy) as usize] = [0; IS_COPY as usize];
+/// assert!(IS_COPY);
+/// ```
+#[macro_export]
+macro_rules! stable_is_trait {
+    ($type:ty, $trait:path) => {{
+        trait Fallback {
+            const HAS: bool = false;
+        }
+
+        impl<T: ?Sized> Fallback for T {}
+
+        struct Test<T: ?Sized>(core::marker::PhantomData<T>);
+
+        impl<T: ?Sized + $trait> Test<T> {
+            const HAS: bool = true;
+        }
+
+        Test::<$type>::HAS
+    }};
+}
+
+/// Asserts, at compile time, that `$type` implements `$trait`, optionally with a custom
+/// failure message.
+///
+/// Builds on the same inherent-const + fallback-trait technique as [`stable_is_trait`] to
+/// compute a `const DOES_IMPL: bool`, then anchors the assertion to a `[(); N]` array whose
+/// length underflows (and so fails to compile) when the bound doesn't hold. A `const` block
+/// running [`assert!`] alongside it is what actually surfaces the message, since a bare
+/// `const` panic can otherwise be deferred past trait resolution and never get evaluated.
+///
+/// ```
+/// use is_trait::assert_impl;
+///
+/// assert_impl!(u32, Copy);
+/// assert_impl!(u32, Copy, "u32 must be Copy");
+/// ```
+#[macro_export]
+macro_rules! assert_impl {
+    ($type:ty, $trait:path) => {
+        $crate::assert_impl!(
+            $type,
+            $trait,
+            ::core::concat!(
+                "`",
+                ::core::stringify!($type),
+                "` does not implement `",
+                ::core::stringify!($trait),
+                "`"
+            )
+        );
+    };
+    ($type:ty, $trait:path, $msg:expr) => {
+        const _: () = {
+            const DOES_IMPL: bool = $crate::stable_is_trait!($type, $trait);
+            const _: [(); 0 - !DOES_IMPL as usize] = [];
+            const { assert!(DOES_IMPL, $msg) };
+        };
+    };
+}
+
+/// Asserts, at compile time, that `$type` does *not* implement `$trait`, optionally with a
+/// custom failure message.
+///
+/// The mirror image of [`assert_impl`]; see its docs for how the underlying check works.
+///
+/// ```
+/// use is_trait::assert_not_impl;
+///
+/// assert_not_impl!(std::rc::Rc<u32>, Send);
+/// assert_not_impl!(std::rc::Rc<u32>, Send, "Rc<u32> must not be Send");
+/// ```
+#[macro_export]
+macro_rules! assert_not_impl {
+    ($type:ty, $trait:path) => {
+        $crate::assert_not_impl!(
+            $type,
+            $trait,
+            ::core::concat!(
+                "`",
+                ::core::stringify!($type),
+                "` implements `",
+                ::core::stringify!($trait),
+                "`"
+            )
+        );
+    };
+    ($type:ty, $trait:path, $msg:expr) => {
+        const _: () = {
+            const DOES_IMPL: bool = $crate::stable_is_trait!($type, $trait);
+            const _: [(); 0 - DOES_IMPL as usize] = [];
+            const { assert!(!DOES_IMPL, $msg) };
+        };
+    };
+}
+
+/// Asserts, at compile time, that `$type` implements *exactly one* of the listed traits.
+///
+/// Each trait is lowered to a [`stable_is_trait`] bool, the bools are summed as `usize`,
+/// and the total is required to equal `1` using the same underflowing-array trick as
+/// [`assert_impl`]. Useful for sealed, type-state-style designs where a type must fall
+/// into precisely one of several mutually exclusive categories.
+///
+/// ```
+/// use is_trait::assert_impl_one;
+///
+/// trait Red {}
+/// trait Green {}
+/// trait Blue {}
+///
+/// struct Apple;
+/// impl Red for Apple {}
+///
+/// assert_impl_one!(Apple, Red, Green, Blue);
+/// ```
+#[macro_export]
+macro_rules! assert_impl_one {
+    ($type:ty, $($trait:path),+ $(,)?) => {
+        const _: () = {
+            const COUNT: usize = 0 $(+ $crate::stable_is_trait!($type, $trait) as usize)+;
+            const _: [(); 0 - (COUNT != 1) as usize] = [];
+            const {
+                assert!(
+                    COUNT == 1,
+                    ::core::concat!(
+                        "`",
+                        ::core::stringify!($type),
+                        "` must implement exactly one of the listed traits"
+                    )
+                )
+            };
+        };
+    };
+}